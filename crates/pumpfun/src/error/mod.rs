@@ -15,15 +15,20 @@
 //! - `AnchorClientError`: An error occurred while interacting with the Anchor client.
 //! - `InvalidInput`: Invalid input parameters were provided.
 //! - `InsufficientFunds`: Insufficient funds for a transaction.
-//! - `SimulationError`: Transaction simulation failed.
+//! - `SimulationError`: Transaction simulation failed; carries the decoded error, program logs, and compute units consumed.
 //! - `RateLimitExceeded`: Rate limit exceeded.
+//! - `Program`: A decoded on-chain program error, identified by its Anchor custom error code.
 
 use anchor_client::solana_client;
 use serde_json::Error;
 use anchor_client::solana_client::{
-    client_error::ClientError as SolanaClientError, 
-    pubsub_client::PubsubClientError
+    client_error::{ClientError as SolanaClientError, ClientErrorKind},
+    pubsub_client::PubsubClientError,
+    rpc_request::{RpcError, RpcResponseErrorData},
+    rpc_response::RpcSimulateTransactionResult,
 };
+use anchor_client::solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+use std::time::Duration;
 
 #[derive(Debug)]
 pub enum ClientError {
@@ -44,9 +49,18 @@ pub enum ClientError {
     /// Insufficient funds for transaction
     InsufficientFunds,
     /// Transaction simulation failed
-    SimulationError(String),
+    SimulationError(SimulationError),
     /// Rate limit exceeded
     RateLimitExceeded,
+    /// A decoded on-chain program error, identified by its Anchor custom
+    /// error code. `source` carries the original `TransactionError` this
+    /// was decoded from, when one was available, so callers can still
+    /// recover the instruction index via [`ClientError::as_transaction_error`].
+    Program {
+        code: u32,
+        name: &'static str,
+        source: Option<TransactionError>,
+    },
 
     Solana(String, String),
     
@@ -80,8 +94,9 @@ impl std::fmt::Display for ClientError {
             Self::AnchorClientError(err) => write!(f, "Anchor client error: {}", err),
             Self::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
             Self::InsufficientFunds => write!(f, "Insufficient funds for transaction"),
-            Self::SimulationError(msg) => write!(f, "Transaction simulation failed: {}", msg),
+            Self::SimulationError(sim) => write!(f, "Transaction simulation failed: {}", sim),
             Self::RateLimitExceeded => write!(f, "Rate limit exceeded"),
+            Self::Program { code, name, .. } => write!(f, "Program error {}: {}", code, name),
             Self::Solana(msg, details) => write!(f, "Solana error: {}, details: {}", msg, details),
             Self::Parse(msg, details) => write!(f, "Parse error: {}, details: {}", msg, details),
             Self::Join(msg) => write!(f, "Task join error: {}", msg),
@@ -103,6 +118,247 @@ impl std::error::Error for ClientError {
             Self::SolanaClientError(err) => Some(err),
             Self::UploadMetadataError(err) => Some(err.as_ref()),
             Self::AnchorClientError(err) => Some(err),
+            Self::SimulationError(sim) => sim.err.as_ref().map(|err| err as &(dyn std::error::Error + 'static)),
+            _ => None,
+        }
+    }
+}
+
+/// Structured result of a failed or erroring transaction simulation.
+///
+/// Carries everything the RPC `simulateTransaction` response provides so
+/// callers can inspect program logs and compute budget directly on the
+/// error value instead of turning on RPC logging out-of-band.
+#[derive(Debug, Clone)]
+pub struct SimulationError {
+    /// The on-chain error the simulation failed with, if any.
+    pub err: Option<TransactionError>,
+    /// Program logs emitted during the simulation.
+    pub logs: Vec<String>,
+    /// Compute units consumed before the simulation stopped.
+    pub units_consumed: Option<u64>,
+    /// Raw return data set by the last instruction, if any.
+    pub return_data: Option<Vec<u8>>,
+}
+
+impl SimulationError {
+    /// Parses an RPC `simulateTransaction` response, given as a raw JSON
+    /// value, into a [`SimulationError`].
+    pub fn from_rpc_value(value: &serde_json::Value) -> ClientResult<Self> {
+        let result: RpcSimulateTransactionResult =
+            serde_json::from_value(value.clone()).map_err(|err| {
+                ClientError::Parse(
+                    "Simulation result parse error".to_string(),
+                    err.to_string(),
+                )
+            })?;
+        Ok(Self::from(result))
+    }
+
+    /// Iterates over the program logs emitted during the simulation.
+    pub fn program_logs(&self) -> impl Iterator<Item = &str> {
+        self.logs.iter().map(String::as_str)
+    }
+
+    /// Decodes the simulation's on-chain error into a named pump.fun
+    /// program error, reusing the same decoder used for real transaction
+    /// failures.
+    pub fn decoded_program_error(&self) -> Option<ClientError> {
+        let te = self.err.as_ref()?;
+        ClientError::from_transaction_error(te)
+    }
+}
+
+impl From<RpcSimulateTransactionResult> for SimulationError {
+    fn from(result: RpcSimulateTransactionResult) -> Self {
+        Self {
+            err: result.err,
+            logs: result.logs.unwrap_or_default(),
+            units_consumed: result.units_consumed,
+            return_data: result
+                .return_data
+                .and_then(|data| decode_base64(&data.data.0)),
+        }
+    }
+}
+
+/// Decodes a standard-alphabet base64 string, as used by the RPC
+/// `simulateTransaction` response's `returnData` field.
+///
+/// Hand-rolled rather than pulling in a `base64` crate for a single call
+/// site; returns `None` on any character outside the base64 alphabet
+/// instead of panicking. A truncated final group is not rejected: its
+/// leftover bits are dropped.
+fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    fn value(byte: u8) -> Option<u8> {
+        match byte {
+            b'A'..=b'Z' => Some(byte - b'A'),
+            b'a'..=b'z' => Some(byte - b'a' + 26),
+            b'0'..=b'9' => Some(byte - b'0' + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let input = input.trim_end_matches('=');
+    let mut out = Vec::with_capacity(input.len() * 3 / 4);
+    let mut bits = 0u32;
+    let mut bit_count = 0u32;
+    for byte in input.bytes() {
+        let v = value(byte)?;
+        bits = (bits << 6) | v as u32;
+        bit_count += 6;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            out.push((bits >> bit_count) as u8);
+        }
+    }
+    Some(out)
+}
+
+impl std::fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.err {
+            Some(err) => write!(
+                f,
+                "{} ({} log lines, {} compute units consumed)",
+                err,
+                self.logs.len(),
+                self.units_consumed.unwrap_or_default()
+            ),
+            None => write!(f, "simulation failed ({} log lines)", self.logs.len()),
+        }
+    }
+}
+
+/// Maps an Anchor custom error code raised by the pump.fun program to a
+/// stable name, so callers can `match` on the decoded error instead of
+/// substring-matching display strings.
+///
+/// Anchor reserves `6000` and up for program-defined errors; codes below
+/// that (100/1000/2000/3000 ranges) are framework-level errors we don't
+/// attempt to name here.
+fn decode_pump_fun_error_name(code: u32) -> &'static str {
+    match code {
+        6000 => "NotAuthorized",
+        6001 => "AlreadyInitialized",
+        6002 => "TooMuchSolRequired",
+        6003 => "TooLittleSolReceived",
+        6004 => "MintDoesNotMatchBondingCurve",
+        6005 => "BondingCurveComplete",
+        6006 => "BondingCurveNotComplete",
+        6007 => "NotInitialized",
+        _ => "unknown",
+    }
+}
+
+impl ClientError {
+    /// Decodes an Anchor `InstructionError::Custom(code)` into a named
+    /// `ClientError::Program` variant, falling back to `"unknown"` for
+    /// codes we don't recognize. Returns `None` for non-`Custom`
+    /// instruction errors, which are better represented by the existing
+    /// transport-level variants.
+    ///
+    /// The returned variant carries no `source`; use
+    /// [`ClientError::from_transaction_error`] when the wrapping
+    /// `TransactionError` is available so it survives on the decoded error.
+    pub fn from_instruction_error(err: &InstructionError) -> Option<ClientError> {
+        match err {
+            InstructionError::Custom(code) => Some(ClientError::Program {
+                code: *code,
+                name: decode_pump_fun_error_name(*code),
+                source: None,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Like [`ClientError::from_instruction_error`], but keeps `source`
+    /// around on the decoded `Program` variant so
+    /// [`ClientError::as_transaction_error`]/[`ClientError::into_transaction_error`]
+    /// can still recover the original `TransactionError` (and its
+    /// instruction index) afterwards.
+    fn from_transaction_error(source: &TransactionError) -> Option<ClientError> {
+        match source {
+            TransactionError::InstructionError(_, instr_err) => {
+                match ClientError::from_instruction_error(instr_err)? {
+                    ClientError::Program { code, name, .. } => Some(ClientError::Program {
+                        code,
+                        name,
+                        source: Some(source.clone()),
+                    }),
+                    other => Some(other),
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Pulls the `TransactionError` out of a `SolanaClientError`, whether it
+/// was raised directly or wrapped in a simulate-transaction preflight
+/// failure.
+fn solana_client_transaction_error(error: &SolanaClientError) -> Option<&TransactionError> {
+    match error.kind() {
+        ClientErrorKind::TransactionError(te) => Some(te),
+        ClientErrorKind::RpcError(RpcError::RpcResponseError {
+            data: RpcResponseErrorData::SendTransactionPreflightFailure(sim_result),
+            ..
+        }) => sim_result.err.as_ref(),
+        _ => None,
+    }
+}
+
+/// Recognizes common transient failure substrings surfaced by the RPC
+/// transport (connection resets, rate-limit/unavailable responses) until
+/// the client exposes these as structured variants.
+///
+/// Deliberately matches on HTTP reason phrases and `status`/`http`-prefixed
+/// status codes rather than bare `"429"`/`"503"` digit strings, since those
+/// digits show up unrelated in plenty of non-retryable errors (lamport
+/// amounts, account indices, slots).
+fn is_transient_transport_message(message: &str) -> bool {
+    const TRANSIENT_MARKERS: [&str; 9] = [
+        "connection reset",
+        "connection closed",
+        "timed out",
+        "too many requests",
+        "service unavailable",
+        "status 429",
+        "status 503",
+        "http 429",
+        "http 503",
+    ];
+    let lower = message.to_lowercase();
+    TRANSIENT_MARKERS.iter().any(|marker| lower.contains(marker))
+}
+
+impl ClientError {
+    /// Returns `true` if the error represents a transient condition that is
+    /// likely to succeed on retry: rate limiting, timeouts, a closed event
+    /// channel, or a network-level Solana RPC failure.
+    ///
+    /// Decoded program errors, invalid input, insufficient funds, and
+    /// duplicate events are never retryable since retrying would just
+    /// reproduce the same failure.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::RateLimitExceeded | Self::Timeout(_, _) | Self::ChannelClosed => true,
+            Self::Solana(_, details) => is_transient_transport_message(details),
+            Self::SolanaClientError(err) => is_transient_transport_message(&err.to_string()),
+            Self::AnchorClientError(anchor_client::ClientError::SolanaClientError(err)) => {
+                is_transient_transport_message(&err.to_string())
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns how long to wait before retrying, if the error carries a
+    /// hint about the right delay.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimitExceeded => Some(Duration::from_millis(500)),
             _ => None,
         }
     }
@@ -110,6 +366,11 @@ impl std::error::Error for ClientError {
 
 impl From<SolanaClientError> for ClientError {
     fn from(error: SolanaClientError) -> Self {
+        if let Some(te) = solana_client_transaction_error(&error) {
+            if let Some(program_err) = ClientError::from_transaction_error(te) {
+                return program_err;
+            }
+        }
         ClientError::Solana(
             "Solana client error".to_string(),
             error.to_string(),
@@ -135,4 +396,226 @@ impl From<Error> for ClientError {
     }
 }
 
-pub type ClientResult<T> = Result<T, ClientError>;
\ No newline at end of file
+impl ClientError {
+    /// Reaches through the Solana/Anchor client error layers to recover the
+    /// underlying on-chain [`TransactionError`], if this error wraps one.
+    pub fn as_transaction_error(&self) -> Option<&TransactionError> {
+        match self {
+            Self::SolanaClientError(err) => solana_client_transaction_error(err),
+            Self::AnchorClientError(anchor_client::ClientError::SolanaClientError(err)) => {
+                solana_client_transaction_error(err)
+            }
+            Self::SimulationError(sim) => sim.err.as_ref(),
+            Self::Program { source, .. } => source.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Consumes the error, returning the underlying [`TransactionError`] if
+    /// it wraps one. See [`ClientError::as_transaction_error`].
+    pub fn into_transaction_error(self) -> Option<TransactionError> {
+        match self {
+            Self::SolanaClientError(err) => solana_client_transaction_error(&err).cloned(),
+            Self::AnchorClientError(anchor_client::ClientError::SolanaClientError(err)) => {
+                solana_client_transaction_error(&err).cloned()
+            }
+            Self::SimulationError(sim) => sim.err,
+            Self::Program { source, .. } => source,
+            _ => None,
+        }
+    }
+}
+
+impl From<ClientError> for std::io::Error {
+    fn from(err: ClientError) -> Self {
+        let kind = match &err {
+            ClientError::RateLimitExceeded | ClientError::Timeout(_, _) => {
+                std::io::ErrorKind::TimedOut
+            }
+            ClientError::InvalidInput(_) => std::io::ErrorKind::InvalidInput,
+            ClientError::BondingCurveNotFound => std::io::ErrorKind::NotFound,
+            ClientError::Duplicate(_) => std::io::ErrorKind::AlreadyExists,
+            ClientError::ChannelClosed => std::io::ErrorKind::BrokenPipe,
+            ClientError::BorshError(io_err) => io_err.kind(),
+            _ => std::io::ErrorKind::Other,
+        };
+        std::io::Error::new(kind, err.to_string())
+    }
+}
+
+impl From<std::io::Error> for ClientError {
+    fn from(err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::TimedOut => {
+                ClientError::Timeout("IO error".to_string(), err.to_string())
+            }
+            _ => ClientError::Other(err.to_string()),
+        }
+    }
+}
+
+pub type ClientResult<T> = Result<T, ClientError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_retryable_matches_transient_conditions() {
+        assert!(ClientError::RateLimitExceeded.is_retryable());
+        assert!(ClientError::Timeout("rpc".to_string(), "slow".to_string()).is_retryable());
+        assert!(ClientError::ChannelClosed.is_retryable());
+        assert!(ClientError::Solana(
+            "rpc".to_string(),
+            "HTTP status 429 Too Many Requests".to_string()
+        )
+        .is_retryable());
+        assert!(!ClientError::InvalidInput("bad amount").is_retryable());
+        assert!(!ClientError::InsufficientFunds.is_retryable());
+    }
+
+    #[test]
+    fn is_retryable_ignores_incidental_digits_and_requires_a_status_context() {
+        assert!(!ClientError::Solana(
+            "rpc".to_string(),
+            "insufficient funds: need 4290000 lamports".to_string()
+        )
+        .is_retryable());
+        assert!(!ClientError::Solana(
+            "rpc".to_string(),
+            "account index 503 out of range".to_string()
+        )
+        .is_retryable());
+        assert!(ClientError::Solana("rpc".to_string(), "503 Service Unavailable".to_string())
+            .is_retryable());
+    }
+
+    #[test]
+    fn retry_after_only_set_for_rate_limit() {
+        assert!(ClientError::RateLimitExceeded.retry_after().is_some());
+        assert!(ClientError::Timeout("rpc".to_string(), "slow".to_string())
+            .retry_after()
+            .is_none());
+    }
+
+    #[test]
+    fn from_instruction_error_decodes_known_pump_fun_codes() {
+        let err = ClientError::from_instruction_error(&InstructionError::Custom(6005)).unwrap();
+        match err {
+            ClientError::Program { code, name, .. } => {
+                assert_eq!(code, 6005);
+                assert_eq!(name, "BondingCurveComplete");
+            }
+            other => panic!("expected Program variant, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn from_instruction_error_falls_back_to_unknown() {
+        let err = ClientError::from_instruction_error(&InstructionError::Custom(1234)).unwrap();
+        match err {
+            ClientError::Program { code, name, .. } => {
+                assert_eq!(code, 1234);
+                assert_eq!(name, "unknown");
+            }
+            other => panic!("expected Program variant, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn from_instruction_error_ignores_non_custom_errors() {
+        assert!(ClientError::from_instruction_error(&InstructionError::InvalidArgument).is_none());
+    }
+
+    #[test]
+    fn decode_base64_round_trips_known_vectors() {
+        assert_eq!(decode_base64("aGVsbG8=").unwrap(), b"hello");
+        assert_eq!(decode_base64("").unwrap(), Vec::<u8>::new());
+        assert!(decode_base64("not base64!!").is_none());
+    }
+
+    #[test]
+    fn simulation_error_from_rpc_value_parses_logs_and_units() {
+        let value = serde_json::json!({
+            "err": null,
+            "logs": ["Program log: buy", "Program log: success"],
+            "accounts": null,
+            "unitsConsumed": 12345,
+            "returnData": null,
+        });
+        let err = SimulationError::from_rpc_value(&value).unwrap();
+        assert_eq!(err.logs.len(), 2);
+        assert_eq!(err.units_consumed, Some(12345));
+        assert_eq!(err.program_logs().count(), 2);
+        assert!(err.decoded_program_error().is_none());
+    }
+
+    #[test]
+    fn simulation_error_from_rpc_value_rejects_malformed_input() {
+        // `logs` must be an array of strings (or absent); a number can
+        // never deserialize into it, regardless of which other fields the
+        // RPC response happens to include.
+        let value = serde_json::json!({ "logs": 42 });
+        assert!(SimulationError::from_rpc_value(&value).is_err());
+    }
+
+    #[test]
+    fn as_transaction_error_reads_through_simulation_error() {
+        let sim = SimulationError::from_rpc_value(&serde_json::json!({
+            "err": {"InstructionError": [0, {"Custom": 6002}]},
+            "logs": [],
+            "accounts": null,
+            "unitsConsumed": null,
+            "returnData": null,
+        }))
+        .unwrap();
+        let err = ClientError::SimulationError(sim);
+        assert!(matches!(
+            err.as_transaction_error(),
+            Some(TransactionError::InstructionError(0, InstructionError::Custom(6002)))
+        ));
+    }
+
+    #[test]
+    fn program_error_from_solana_client_error_keeps_transaction_error_source() {
+        let solana_err = SolanaClientError::new_with_request(
+            ClientErrorKind::TransactionError(TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(6003),
+            )),
+            solana_client::rpc_request::RpcRequest::SendTransaction,
+        );
+        let err = ClientError::from(solana_err);
+        match &err {
+            ClientError::Program { code, name, source } => {
+                assert_eq!(*code, 6003);
+                assert_eq!(*name, "TooLittleSolReceived");
+                assert!(source.is_some());
+            }
+            other => panic!("expected Program variant, got {other:?}"),
+        }
+        assert!(matches!(
+            err.as_transaction_error(),
+            Some(TransactionError::InstructionError(1, InstructionError::Custom(6003)))
+        ));
+        assert!(matches!(
+            err.into_transaction_error(),
+            Some(TransactionError::InstructionError(1, InstructionError::Custom(6003)))
+        ));
+    }
+
+    #[test]
+    fn io_error_conversion_maps_retryable_errors_to_timed_out() {
+        let io_err: std::io::Error = ClientError::RateLimitExceeded.into();
+        assert_eq!(io_err.kind(), std::io::ErrorKind::TimedOut);
+
+        let io_err: std::io::Error = ClientError::InvalidInput("bad amount").into();
+        assert_eq!(io_err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn client_error_from_io_error_round_trips_timeout() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::TimedOut, "deadline exceeded");
+        assert!(matches!(ClientError::from(io_err), ClientError::Timeout(_, _)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,144 @@
+//! Exponential-backoff retry helper built on [`ClientError::is_retryable`].
+//!
+//! Most pump.fun SDK calls go out over public RPC endpoints that rate-limit
+//! or time out under load. [`with_backoff`] lets callers re-run an
+//! operation with exponential backoff + jitter instead of hand-rolling a
+//! retry loop around every call site.
+
+use std::time::Duration;
+
+use crate::error::{ClientError, ClientResult};
+
+/// Exponential backoff policy for [`with_backoff`].
+#[derive(Debug, Clone, Copy)]
+pub struct BackoffPolicy {
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Upper bound on any single delay, before jitter is added.
+    pub max_delay: Duration,
+    /// Maximum number of attempts, including the first.
+    pub max_attempts: u32,
+}
+
+impl Default for BackoffPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl BackoffPolicy {
+    /// Computes the delay before retrying after the given (zero-indexed)
+    /// attempt, as exponential backoff capped at `max_delay` plus a small
+    /// random jitter so callers hitting the same rate limit at the same
+    /// time (e.g. many bot instances against one public RPC endpoint)
+    /// don't all retry at the identical delay.
+    pub(crate) fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay.saturating_mul(1u32 << attempt.min(16));
+        exp.min(self.max_delay) + Duration::from_millis(jitter_millis())
+    }
+}
+
+/// A 0-99ms random offset taken from a new
+/// [`std::collections::hash_map::RandomState`] on every call (std seeds its
+/// keys from the OS once per thread and advances them for each new
+/// `RandomState`), so it varies per call and per caller rather than just
+/// per attempt number — a dependency-free
+/// stand-in for pulling in a `rand`/`fastrand` crate.
+fn jitter_millis() -> u64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+    RandomState::new().build_hasher().finish() % 100
+}
+
+/// Re-runs `op` with exponential backoff + jitter while its error is
+/// [`ClientError::is_retryable`], honoring [`ClientError::retry_after`]
+/// when the error provides one, up to `policy.max_attempts`.
+pub async fn with_backoff<F, Fut, T>(policy: BackoffPolicy, mut op: F) -> ClientResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = ClientResult<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt + 1 < policy.max_attempts && err.is_retryable() => {
+                let delay = err
+                    .retry_after()
+                    .unwrap_or_else(|| policy.delay_for_attempt(attempt));
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_for_attempt_grows_exponentially_and_caps_at_max_delay() {
+        let policy = BackoffPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            max_attempts: 10,
+        };
+        // jitter is 0-99ms, so check the un-jittered floor and the capped ceiling.
+        assert!(policy.delay_for_attempt(0) >= Duration::from_millis(100));
+        assert!(policy.delay_for_attempt(0) < Duration::from_millis(200));
+        assert!(policy.delay_for_attempt(1) >= Duration::from_millis(200));
+        assert!(policy.delay_for_attempt(1) < Duration::from_millis(300));
+        // 100ms * 2^10 would overflow past max_delay; it must be capped.
+        assert!(policy.delay_for_attempt(10) < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn jitter_varies_across_calls() {
+        let samples: std::collections::HashSet<u64> = (0..20).map(|_| jitter_millis()).collect();
+        assert!(
+            samples.len() > 1,
+            "expected jitter to vary across calls, got a single repeated value"
+        );
+    }
+
+    #[tokio::test]
+    async fn with_backoff_retries_retryable_errors_then_succeeds() {
+        let policy = BackoffPolicy {
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            max_attempts: 5,
+        };
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+        let result = with_backoff(policy, || {
+            let attempt = attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async move {
+                if attempt < 2 {
+                    Err(ClientError::RateLimitExceeded)
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn with_backoff_does_not_retry_non_retryable_errors() {
+        let policy = BackoffPolicy::default();
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+        let result: ClientResult<()> = with_backoff(policy, || {
+            attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async { Err(ClientError::InvalidInput("bad input")) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+}
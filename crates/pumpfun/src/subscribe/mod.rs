@@ -0,0 +1,330 @@
+//! Resilient subscription management for the pump.fun pubsub event feed.
+//!
+//! The raw WebSocket pubsub client is fire-and-forget: a dropped connection
+//! just closes the channel and leaves the caller to notice and rebuild its
+//! subscriptions by hand. [`SubscriptionManager`] wraps it with automatic
+//! reconnection, subscription-filter replay, and per-event dedup, so a
+//! trading bot survives RPC node drops instead of going dark.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+
+use anchor_client::solana_client::pubsub_client::PubsubClient;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use tokio::sync::{mpsc, Mutex};
+
+use crate::error::{ClientError, ClientResult};
+use crate::events::PumpFunEvent;
+use crate::retry::BackoffPolicy;
+
+/// A single active subscription filter, replayed against the pubsub client
+/// after every reconnect.
+#[derive(Debug, Clone)]
+pub struct SubscriptionFilter {
+    /// Account (e.g. a bonding curve or mint) whose events to stream.
+    pub account: Pubkey,
+}
+
+/// Policy controlling how a [`SubscriptionManager`] reconnects and buffers
+/// events.
+#[derive(Debug, Clone)]
+pub struct SubscriptionPolicy {
+    /// Backoff applied between reconnect attempts.
+    pub backoff: BackoffPolicy,
+    /// Maximum number of reconnect attempts before giving up and closing
+    /// the event stream.
+    pub max_reconnect_attempts: u32,
+    /// Capacity of the in-flight event buffer.
+    pub max_buffer: usize,
+    /// How many recently seen event ids to remember for dedup; `0`
+    /// disables dedup.
+    pub dedup_window: usize,
+}
+
+impl Default for SubscriptionPolicy {
+    fn default() -> Self {
+        Self {
+            backoff: BackoffPolicy::default(),
+            max_reconnect_attempts: 10,
+            max_buffer: 1024,
+            dedup_window: 256,
+        }
+    }
+}
+
+/// A decoded pump.fun pubsub event, tagged with a stable id used for dedup
+/// across reconnects.
+#[derive(Debug, Clone)]
+pub struct SubscriptionEvent {
+    /// Id used to dedup this event across reconnects: the filter's account
+    /// and the update's slot (see `update_id`).
+    pub id: String,
+    /// The decoded event payload.
+    pub event: PumpFunEvent,
+}
+
+/// Builds a per-update dedup id from the filter's account and the update's
+/// slot, so repeated updates to the same account get distinct ids instead
+/// of all colliding on the account address and being dropped as duplicates
+/// after the first one.
+fn update_id(account: &Pubkey, slot: u64) -> String {
+    format!("{account}:{slot}")
+}
+
+/// Returns `true` if a `serde_json` deserialization failure is serde's
+/// "unknown variant" error — i.e. the payload had the right shape but its
+/// discriminator doesn't match any [`PumpFunEvent`] variant, as opposed to
+/// being malformed JSON or missing fields entirely.
+fn is_unknown_variant_error(err: &serde_json::Error) -> bool {
+    err.to_string().contains("unknown variant")
+}
+
+/// Manages a long-lived pump.fun subscription: reconnects on
+/// [`ClientError::ChannelClosed`]/pubsub transport failures, replays the
+/// active filters, and emits a typed, deduped stream of decoded events.
+pub struct SubscriptionManager {
+    ws_url: String,
+    policy: SubscriptionPolicy,
+    filters: Mutex<Vec<SubscriptionFilter>>,
+    seen_order: Mutex<VecDeque<String>>,
+    seen: Mutex<HashSet<String>>,
+}
+
+impl SubscriptionManager {
+    /// Creates a manager for the given pubsub websocket endpoint.
+    pub fn new(ws_url: impl Into<String>, policy: SubscriptionPolicy) -> Self {
+        Self {
+            ws_url: ws_url.into(),
+            policy,
+            filters: Mutex::new(Vec::new()),
+            seen_order: Mutex::new(VecDeque::new()),
+            seen: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Adds a subscription filter. Active filters are replayed against the
+    /// pubsub client on every reconnect.
+    pub async fn subscribe(&self, filter: SubscriptionFilter) {
+        self.filters.lock().await.push(filter);
+    }
+
+    /// Starts the managed feed, returning a receiver of decoded events.
+    ///
+    /// The stream never closes on a transient transport failure: it
+    /// reconnects with the configured [`BackoffPolicy`] and replays the
+    /// active filters, up to `policy.max_reconnect_attempts`. Once that
+    /// limit is hit the last error is sent and the channel is closed.
+    pub fn start(self: Arc<Self>) -> mpsc::Receiver<ClientResult<SubscriptionEvent>> {
+        let (tx, rx) = mpsc::channel(self.policy.max_buffer);
+        tokio::spawn(async move {
+            let mut attempt = 0;
+            loop {
+                match self.run_once(&tx).await {
+                    Ok(()) => break,
+                    Err(err) if attempt < self.policy.max_reconnect_attempts && err.is_retryable() => {
+                        let delay = err
+                            .retry_after()
+                            .unwrap_or_else(|| self.policy.backoff.delay_for_attempt(attempt));
+                        attempt += 1;
+                        tokio::time::sleep(delay).await;
+                    }
+                    Err(err) => {
+                        let _ = tx.send(Err(err)).await;
+                        break;
+                    }
+                }
+            }
+        });
+        rx
+    }
+
+    /// Connects once, replays the active filters, and forwards decoded
+    /// events until the underlying pubsub connection drops.
+    async fn run_once(&self, tx: &mpsc::Sender<ClientResult<SubscriptionEvent>>) -> ClientResult<()> {
+        let mut raw_rx = self.connect().await?;
+        while let Some(item) = raw_rx.recv().await {
+            if tx.is_closed() {
+                return Ok(());
+            }
+            let decoded = match item {
+                Ok((id, payload)) => self.decode_event(id, &payload).await,
+                Err(err) => Err(err),
+            };
+            if tx.send(decoded).await.is_err() {
+                return Ok(());
+            }
+        }
+        Err(ClientError::ChannelClosed)
+    }
+
+    /// Opens one `accountSubscribe` WebSocket subscription per active
+    /// filter against the pubsub endpoint, replaying the whole filter set,
+    /// and merges their updates onto a single raw channel for
+    /// [`Self::run_once`] to decode.
+    ///
+    /// Each filter gets its own blocking subscription thread (the pubsub
+    /// client's `recv()` is synchronous); they all forward into the same
+    /// channel, so losing one filter's connection surfaces as a channel
+    /// item carrying that filter's error rather than tearing down the
+    /// others. With no active filters there is nothing to subscribe to,
+    /// so this reports a closed channel immediately.
+    async fn connect(&self) -> ClientResult<mpsc::Receiver<ClientResult<(String, serde_json::Value)>>> {
+        let filters = self.filters.lock().await.clone();
+        if filters.is_empty() {
+            return Err(ClientError::ChannelClosed);
+        }
+
+        let (raw_tx, raw_rx) = mpsc::channel(self.policy.max_buffer.max(1));
+        for filter in filters {
+            let raw_tx = raw_tx.clone();
+            let ws_url = self.ws_url.clone();
+            tokio::task::spawn_blocking(move || {
+                let (_client, receiver) =
+                    match PubsubClient::account_subscribe(&ws_url, &filter.account, None) {
+                        Ok(pair) => pair,
+                        Err(err) => {
+                            let _ = raw_tx.blocking_send(Err(ClientError::from(err)));
+                            return;
+                        }
+                    };
+                // `_client` is kept alive for the loop's duration: dropping
+                // it tears down the subscription and stops `receiver`.
+                while let Ok(update) = receiver.recv() {
+                    let id = update_id(&filter.account, update.context.slot);
+                    let payload = match serde_json::to_value(&update) {
+                        Ok(value) => Ok((id, value)),
+                        Err(err) => Err(ClientError::from(err)),
+                    };
+                    if raw_tx.blocking_send(payload).is_err() {
+                        return;
+                    }
+                }
+                // This filter's connection dropped; report it as this filter's error.
+                let _ = raw_tx.blocking_send(Err(ClientError::ChannelClosed));
+            });
+        }
+        Ok(raw_rx)
+    }
+
+    /// Decodes a raw pubsub payload into a [`SubscriptionEvent`], applying
+    /// the dedup window.
+    ///
+    /// Malformed payloads become [`ClientError::Parse`]; payloads whose
+    /// shape is otherwise fine but whose event discriminator isn't one
+    /// [`PumpFunEvent`] recognizes become [`ClientError::InvalidEventType`];
+    /// events already seen within the dedup window become
+    /// [`ClientError::Duplicate`].
+    async fn decode_event(&self, id: String, payload: &serde_json::Value) -> ClientResult<SubscriptionEvent> {
+        if self.is_duplicate(&id).await {
+            return Err(ClientError::Duplicate(id));
+        }
+        match serde_json::from_value::<PumpFunEvent>(payload.clone()) {
+            Ok(event) => Ok(SubscriptionEvent { id, event }),
+            Err(err) if is_unknown_variant_error(&err) => Err(ClientError::InvalidEventType),
+            Err(err) => Err(ClientError::Parse(
+                "Malformed pump.fun event payload".to_string(),
+                err.to_string(),
+            )),
+        }
+    }
+
+    /// Records `id` as seen, evicting the oldest entry once the dedup
+    /// window is full. Returns `true` if `id` was already seen.
+    async fn is_duplicate(&self, id: &str) -> bool {
+        if self.policy.dedup_window == 0 {
+            return false;
+        }
+        let mut seen = self.seen.lock().await;
+        if !seen.insert(id.to_string()) {
+            return true;
+        }
+        let mut seen_order = self.seen_order.lock().await;
+        seen_order.push_back(id.to_string());
+        if seen_order.len() > self.policy.dedup_window {
+            if let Some(oldest) = seen_order.pop_front() {
+                seen.remove(&oldest);
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager(dedup_window: usize) -> SubscriptionManager {
+        SubscriptionManager::new(
+            "wss://example.invalid",
+            SubscriptionPolicy {
+                dedup_window,
+                ..SubscriptionPolicy::default()
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn is_duplicate_flags_repeated_ids() {
+        let manager = manager(256);
+        assert!(!manager.is_duplicate("sig-1").await);
+        assert!(manager.is_duplicate("sig-1").await);
+        assert!(!manager.is_duplicate("sig-2").await);
+    }
+
+    #[tokio::test]
+    async fn is_duplicate_disabled_when_window_is_zero() {
+        let manager = manager(0);
+        assert!(!manager.is_duplicate("sig-1").await);
+        assert!(!manager.is_duplicate("sig-1").await);
+    }
+
+    #[tokio::test]
+    async fn is_duplicate_evicts_oldest_once_window_is_full() {
+        let manager = manager(2);
+        assert!(!manager.is_duplicate("sig-1").await);
+        assert!(!manager.is_duplicate("sig-2").await);
+        // Pushes the window (capacity 2) past its limit, evicting "sig-1".
+        assert!(!manager.is_duplicate("sig-3").await);
+        // "sig-1" was evicted, so it's treated as new again; this insert in
+        // turn evicts "sig-2".
+        assert!(!manager.is_duplicate("sig-1").await);
+        // "sig-3" and the re-inserted "sig-1" are still inside the window.
+        assert!(manager.is_duplicate("sig-3").await);
+        assert!(manager.is_duplicate("sig-1").await);
+    }
+
+    #[tokio::test]
+    async fn connect_reports_closed_channel_with_no_active_filters() {
+        let manager = manager(256);
+        assert!(matches!(
+            manager.connect().await,
+            Err(ClientError::ChannelClosed)
+        ));
+    }
+
+    #[test]
+    fn update_id_varies_by_slot_not_just_account() {
+        let account = Pubkey::new_unique();
+        assert_ne!(update_id(&account, 1), update_id(&account, 2));
+        assert_eq!(update_id(&account, 1), update_id(&account, 1));
+    }
+
+    #[derive(serde::Deserialize)]
+    #[serde(tag = "type")]
+    enum TestDiscriminatedEvent {
+        A,
+        B,
+    }
+
+    #[test]
+    fn is_unknown_variant_error_detects_serde_unknown_variant() {
+        let err = serde_json::from_value::<TestDiscriminatedEvent>(
+            serde_json::json!({ "type": "NotARealVariant" }),
+        )
+        .unwrap_err();
+        assert!(is_unknown_variant_error(&err));
+
+        let err = serde_json::from_value::<TestDiscriminatedEvent>(serde_json::json!(42)).unwrap_err();
+        assert!(!is_unknown_variant_error(&err));
+    }
+}